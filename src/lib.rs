@@ -1,4 +1,18 @@
-use std::str::FromStr;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::str::FromStr;
+
+/// The maximum length of an NMEA 0183 sentence, in bytes. Used as the capacity
+/// of the fixed-size sentence buffer in `no_std` builds.
+pub const NMEA_MAX_LEN: usize = 82;
+
+/// The owned storage for a parsed sentence. With the default `std` feature this
+/// is a heap-allocated `String`; in a `no_std` build it is a fixed-capacity
+/// `heapless::String` so the whole parse path is allocation-free.
+#[cfg(feature = "std")]
+type SentenceBuf = String;
+#[cfg(not(feature = "std"))]
+type SentenceBuf = heapless::String<NMEA_MAX_LEN>;
 
 /// A trait for converting an NMEA object to a GP data struct
 pub trait FromNmea {
@@ -24,9 +38,10 @@ pub struct GpsDate {
 /// A struct for holding an NMEA sentence
 #[derive(Debug)]
 pub struct NMEA {
-    pub sentence: String,        // The sentence itself
-    pub checksum: u8,            // The single byte checksum
-    pub sentence_type: NmeaType, // The type of NMEA sentence (GPGGA, GPRMC, GPZDA, etc)
+    pub sentence: SentenceBuf,           // The sentence itself
+    pub checksum: u8,                    // The single byte checksum
+    pub sentence_type: NmeaType,         // The type of NMEA sentence (GGA, RMC, ZDA, etc)
+    pub nav_system: NavigationSystem,    // The navigation system the sentence came from
 }
 
 /// An enum for the type of an NMEA sentence
@@ -36,6 +51,24 @@ pub enum NmeaType {
     GPGGA,
     GPRMC,
     GPZDA,
+    GPGLL,
+    GPVTG,
+    GPGSA,
+    GPGSV,
+    Unknown,
+}
+
+/// An enum for the navigation system (constellation) a sentence came from,
+/// determined from the two-character NMEA talker ID
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NavigationSystem {
+    GPS,
+    GLONASS,
+    Galileo,
+    BeiDou,
+    QZNSS,
+    Combined,
     Unknown,
 }
 
@@ -59,10 +92,23 @@ pub enum RmcStatus {
     Void,
 }
 
+/// An enum for the FAA mode indicator carried by NMEA 2.3+ RMC sentences
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum FaaMode {
+    Autonomous,   // A
+    Differential, // D
+    Estimated,    // E (estimated / dead-reckoning)
+    NotValid,     // N
+    RtkInteger,   // R
+    RtkFloat,     // F
+}
+
 /// All the data fields from a GPGGA sentence
 #[allow(dead_code)]
 #[derive(Debug, Default)]
 pub struct GPGGA {
+    pub nav_system:       Option<NavigationSystem>,
     pub time:             Option<GpsTime>,
     pub latitude:         Option<f64>,
     pub longitude:        Option<f64>,
@@ -79,6 +125,7 @@ pub struct GPGGA {
 #[allow(dead_code)]
 #[derive(Debug, Default)]
 pub struct GPZDA {
+    pub nav_system:   Option<NavigationSystem>,
     pub time:         Option<GpsTime>,
     pub date:         Option<GpsDate>,
     pub zone_hours:   Option<u8>,
@@ -89,6 +136,7 @@ pub struct GPZDA {
 #[allow(dead_code)]
 #[derive(Debug, Default)]
 pub struct GPRMC {
+    pub nav_system: Option<NavigationSystem>,
     pub time:       Option<GpsTime>,
     pub rmc_status: Option<RmcStatus>,
     pub latitude:   Option<f64>,
@@ -97,6 +145,174 @@ pub struct GPRMC {
     pub cog:        Option<f64>,
     pub date:       Option<GpsDate>,
     pub magvar:     Option<f64>,
+    pub faa_mode:   Option<FaaMode>,
+}
+
+/// An enum for the satellite selection mode of a GPGSA message
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum GsaMode {
+    Manual,
+    Automatic,
+}
+
+/// An enum for the fix type reported by a GPGSA message
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum GsaFix {
+    NoFix,
+    Fix2D,
+    Fix3D,
+}
+
+/// All the data fields from a GPGLL sentence
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct GPGLL {
+    pub nav_system: Option<NavigationSystem>,
+    pub latitude:   Option<f64>,
+    pub longitude:  Option<f64>,
+    pub time:       Option<GpsTime>,
+    pub status:     Option<RmcStatus>,
+}
+
+/// All the data fields from a GPVTG sentence
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct GPVTG {
+    pub nav_system:   Option<NavigationSystem>,
+    pub cog_true:     Option<f64>,
+    pub cog_magnetic: Option<f64>,
+    pub sog_knots:    Option<f64>,
+    pub sog_kmh:      Option<f64>,
+}
+
+/// All the data fields from a GPGSA sentence
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct GPGSA {
+    pub nav_system: Option<NavigationSystem>,
+    pub mode:       Option<GsaMode>,
+    pub fix:        Option<GsaFix>,
+    pub satellites: [Option<u8>; 12],
+    pub pdop:       Option<f64>,
+    pub hdop:       Option<f64>,
+    pub vdop:       Option<f64>,
+}
+
+/// A single satellite as reported in the sky view of a GPGSV sentence
+#[allow(dead_code)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SatelliteInView {
+    pub prn:       Option<u8>,
+    pub elevation: Option<u8>,
+    pub azimuth:   Option<u16>,
+    pub snr:       Option<u8>,
+}
+
+/// All the data fields from a GPGSV sentence
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct GPGSV {
+    pub nav_system:         Option<NavigationSystem>,
+    pub total_messages:     Option<u8>,
+    pub message_number:     Option<u8>,
+    pub satellites_in_view: Option<u8>,
+    pub satellites:         [Option<SatelliteInView>; 4],
+}
+
+/// A running "current best fix" accumulated from a stream of NMEA sentences.
+///
+/// Each individual `FromNmea` struct is only a snapshot of a single sentence,
+/// so a unified fix has to be stitched together from the time in ZDA, the
+/// position in GGA, and the speed/course in RMC. `GpsState` does that stitching:
+/// feed it every sentence with `update`, then poll its fields for the latest
+/// value seen for each quantity.
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct GpsState {
+    pub time:           Option<GpsTime>,
+    pub date:           Option<GpsDate>,
+    pub latitude:       Option<f64>,
+    pub longitude:      Option<f64>,
+    pub altitude:       Option<f64>,
+    pub quality:        Option<GpsFixQuality>,
+    pub num_satellites: Option<u8>,
+    pub hdop:           Option<f64>,
+    pub sog:            Option<f64>,
+    pub cog:            Option<f64>,
+    pub magvar:         Option<f64>,
+}
+
+/// Implement some methods for the GpsState struct
+#[allow(dead_code)]
+impl GpsState {
+    /// Create an empty GpsState with every field set to None
+    pub fn new() -> GpsState {
+        GpsState::default()
+    }
+
+    /// Merge a single NMEA sentence into the accumulated fix.
+    ///
+    /// The sentence is dispatched on its `sentence_type`, parsed into the
+    /// matching struct, and any `Some` values are copied into the state.
+    /// Fields the sentence does not carry are left untouched, so a stale but
+    /// still-valid value survives until a newer sentence replaces it.
+    pub fn update(&mut self, nmea: &NMEA) {
+        match nmea.sentence_type {
+            NmeaType::GPGGA => {
+                let gga = GPGGA::from_nmea(nmea);
+                merge_field(&mut self.time,           gga.time);
+                merge_field(&mut self.latitude,       gga.latitude);
+                merge_field(&mut self.longitude,      gga.longitude);
+                merge_field(&mut self.altitude,       gga.altitude);
+                merge_field(&mut self.quality,        gga.quality);
+                merge_field(&mut self.num_satellites, gga.num_satellites);
+                merge_field(&mut self.hdop,           gga.hdop);
+            },
+            NmeaType::GPRMC => {
+                let rmc = GPRMC::from_nmea(nmea);
+                merge_field(&mut self.time,      rmc.time);
+                merge_field(&mut self.latitude,  rmc.latitude);
+                merge_field(&mut self.longitude, rmc.longitude);
+                merge_field(&mut self.sog,       rmc.sog);
+                merge_field(&mut self.cog,       rmc.cog);
+                self.merge_date(rmc.date);
+                merge_field(&mut self.magvar,    rmc.magvar);
+            },
+            NmeaType::GPZDA => {
+                let zda = GPZDA::from_nmea(nmea);
+                merge_field(&mut self.time, zda.time);
+                self.merge_date(zda.date);
+            },
+            _ => {},
+        }
+    }
+
+    /// Merge a freshly parsed date into the accumulated fix, detecting a
+    /// century rollover: if windowing a two-digit year demonstrably moved the
+    /// date *backwards* past the previously stored one (e.g. a device crossing
+    /// 2099→2100, where `00` windows to 2000), bump it forward by 100 so time
+    /// keeps moving monotonically across the year-2100 boundary.
+    ///
+    /// The ordinary 1999→2000 transition is *not* a wrap — there `00` windows
+    /// to 2000, which is still ahead of the stored 1999 — so it must be left
+    /// alone. Gating on `new.year < previous.year` distinguishes the two: only
+    /// a genuine wrap produces a windowed year that precedes the stored one.
+    fn merge_date(&mut self, new: Option<GpsDate>) {
+        let mut new = match new {
+            Some(date) => date,
+            None => return,
+        };
+
+        if let Some(previous) = self.date {
+            if previous.year % 100 == 99 && new.year % 100 == 0 && new.year < previous.year {
+                new.year += 100;
+            }
+        }
+
+        self.date = Some(new);
+    }
 }
 
 /// Implement some methods for the NMEA struct
@@ -131,30 +347,34 @@ impl FromStr for NMEA {
     type Err = u32;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use std::u8;
-
-        //Basically, we need to search the input for a valid NMEA string
-        //Get the characters between the first '$' and '*'
-        let sentence = s.chars()
-                            .skip_while(|&x| x != '$') //Search for the first '$'
-                            .skip(1)                   //Skip the '$'
-                            .take_while(|&x| x != '*') //Get all the characters until the '*'
-                            .collect::<String>();      //Collect the result into a string
+        use core::fmt::Write;
+
+        //Basically, we need to search the input for a valid NMEA string.
+        //Get the characters between the first '$' and '*' and push them into the
+        //sentence buffer (a fixed-capacity, heap-free buffer in no_std builds).
+        let mut sentence = SentenceBuf::new();
+        for c in s.chars()
+                    .skip_while(|&x| x != '$') //Search for the first '$'
+                    .skip(1)                   //Skip the '$'
+                    .take_while(|&x| x != '*') //Get all the characters until the '*'
+        {
+            //A valid NMEA 0183 sentence can never exceed NMEA_MAX_LEN bytes, so an
+            //overflow of the no_std buffer means the input is malformed. Return
+            //Err(4) instead of silently truncating it. The std String never
+            //overflows, so there the write cannot fail.
+            sentence.write_char(c).map_err(|_| 4u32)?;
+        }
 
         //Check if we had any characters returned at all
         if sentence.len() == 0 {
             return Err(1);
         }
 
-        //Get the 1 byte string checksum as a two-character string
-        let ch = s.chars()
-            .skip_while(|&x| x != '*')
-            .skip(1)
-            .take(2)
-            .collect::<String>();
+        //Get the 1 byte string checksum as the two hex characters after the '*'
+        let ch = s.split('*').nth(1).and_then(|c| c.get(0..2)).unwrap_or("");
 
         //Now convert the hex. Return Err(2) if it fails
-        let cs = match u8::from_str_radix(ch.as_str(), 16) {
+        let cs = match u8::from_str_radix(ch, 16) {
             Ok(c)  => c,
             Err(_) => return Err(2),
         };
@@ -165,11 +385,13 @@ impl FromStr for NMEA {
         }
 
         let sentence_type = get_nmea_type(&sentence);
+        let nav_system    = get_nav_system(&sentence);
 
         Ok(NMEA {
             sentence: sentence,
             checksum: cs,
             sentence_type: sentence_type,
+            nav_system: nav_system,
         })
     }
 }
@@ -179,15 +401,39 @@ impl FromStr for NMEA {
 impl FromStr for NmeaType {
     type Err = u32;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //`s` is the 3-character sentence code (GGA/RMC/ZDA/...), with the
+        //2-character talker ID already stripped off, so that sentences from
+        //any constellation resolve to the same NmeaType
         Ok(match s {
-            "GPGGA" => NmeaType::GPGGA,
-            "GPRMC" => NmeaType::GPRMC,
-            "GPZDA" => NmeaType::GPZDA,
+            "GGA" => NmeaType::GPGGA,
+            "RMC" => NmeaType::GPRMC,
+            "ZDA" => NmeaType::GPZDA,
+            "GLL" => NmeaType::GPGLL,
+            "VTG" => NmeaType::GPVTG,
+            "GSA" => NmeaType::GPGSA,
+            "GSV" => NmeaType::GPGSV,
             _ => NmeaType::Unknown,
         })
     }
 }
 
+/// Here we implement the FromStr trait for NavigationSystem so that we can
+/// determine which constellation a sentence came from by parsing its talker ID
+impl FromStr for NavigationSystem {
+    type Err = u32;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "GP" => NavigationSystem::GPS,
+            "GL" => NavigationSystem::GLONASS,
+            "GA" => NavigationSystem::Galileo,
+            "GB" => NavigationSystem::BeiDou,
+            "GQ" => NavigationSystem::QZNSS,
+            "GN" => NavigationSystem::Combined,
+            _ => NavigationSystem::Unknown,
+        })
+    }
+}
+
 /// Here we implement the FromStr trait for RmcStatus so that we can determine
 /// the status of the GPRMC fix by calling parse()
 impl FromStr for RmcStatus {
@@ -201,6 +447,50 @@ impl FromStr for RmcStatus {
     }
 }
 
+/// Here we implement the FromStr trait for FaaMode so that we can determine
+/// the FAA mode indicator of a GPRMC message by calling parse()
+impl FromStr for FaaMode {
+    type Err = u32;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(FaaMode::Autonomous),
+            "D" => Ok(FaaMode::Differential),
+            "E" => Ok(FaaMode::Estimated),
+            "N" => Ok(FaaMode::NotValid),
+            "R" => Ok(FaaMode::RtkInteger),
+            "F" => Ok(FaaMode::RtkFloat),
+            _   => Err(1),
+        }
+    }
+}
+
+/// Here we implement the FromStr trait for GsaMode so that we can determine
+/// whether a GPGSA fix is manually or automatically selected by calling parse()
+impl FromStr for GsaMode {
+    type Err = u32;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "M" => Ok(GsaMode::Manual),
+            "A" => Ok(GsaMode::Automatic),
+            _   => Err(1),
+        }
+    }
+}
+
+/// Here we implement the FromStr trait for GsaFix so that we can determine
+/// the fix type of a GPGSA message by calling parse()
+impl FromStr for GsaFix {
+    type Err = u32;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(GsaFix::NoFix),
+            "2" => Ok(GsaFix::Fix2D),
+            "3" => Ok(GsaFix::Fix3D),
+            _   => Err(1),
+        }
+    }
+}
+
 /// Here we implement the FromStr trait for GpsFixQuality so that we can determine
 /// the type of GPS fix we have by calling parse()
 impl FromStr for GpsFixQuality {
@@ -224,19 +514,19 @@ impl FromStr for GpsTime {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
 
         //Get the hours
-        let h = match s.chars().take(2).collect::<String>().parse::<u8>() {
+        let h = match s.get(0..2).unwrap_or("").parse::<u8>() {
             Ok(c) => c,
             Err(_) => return Err(1),
         };
 
         //Get the minutes
-        let m = match s.chars().skip(2).take(2).collect::<String>().parse::<u8>() {
+        let m = match s.get(2..4).unwrap_or("").parse::<u8>() {
             Ok(c) => c,
             Err(_) => return Err(2),
         };
 
         //Get the seconds
-        let sec = match s.chars().skip(4).collect::<String>().parse::<f32>() {
+        let sec = match s.get(4..).unwrap_or("").parse::<f32>() {
             Ok(c) => c,
             Err(_) => return Err(3),
         };
@@ -261,25 +551,26 @@ impl FromStr for GpsDate {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
 
         //Get the day
-        let day = match s.chars().take(2).collect::<String>().parse::<u8>() {
+        let day = match s.get(0..2).unwrap_or("").parse::<u8>() {
             Ok(c) => c,
             Err(_) => return Err(1),
         };
 
         //Get the month
-        let mon = match s.chars().skip(2).take(2).collect::<String>().parse::<u8>() {
+        let mon = match s.get(2..4).unwrap_or("").parse::<u8>() {
             Ok(c) => c,
             Err(_) => return Err(2),
         };
 
         //Get the year
-        let year = match s.chars().skip(4).collect::<String>().parse::<u16>() {
+        let year = match s.get(4..).unwrap_or("").parse::<u16>() {
             Ok(c) => c,
             Err(_) => return Err(3),
         };
 
-        //Return the parsed date, and an error if the date is invalid
-        GpsDate::new(mon, day, year + 1900).ok_or(4)
+        //Return the parsed date, and an error if the date is invalid.
+        //Resolve the two-digit year into a full four-digit year first.
+        GpsDate::new(mon, day, window_two_digit_year(year)).ok_or(4)
     }
 }
 
@@ -318,6 +609,7 @@ impl FromNmea for GPGGA {
         let dstation_id      = x.next().unwrap().parse::<u16>().ok();
 
         GPGGA {
+            nav_system:       Some(nmea_string.nav_system),
             time:             time,
             latitude:         latitude,
             longitude:        longitude,
@@ -367,6 +659,7 @@ impl FromNmea for GPZDA {
         };
 
         GPZDA {
+            nav_system:   Some(nmea_string.nav_system),
             time:         time,
             date:         date,
             zone_hours:   zone_hours,
@@ -406,7 +699,13 @@ impl FromNmea for GPRMC {
          let date       = x.next().unwrap().parse::<GpsDate>().ok();
          let magvar     = parse_nmea_magvar(x.next().unwrap(), x.next().unwrap());
 
+         //NMEA 2.3+ sentences append an FAA mode indicator after the magnetic
+         //variation. Legacy 11-field sentences omit it, in which case next()
+         //yields None and faa_mode stays None.
+         let faa_mode   = x.next().and_then(|f| f.parse::<FaaMode>().ok());
+
          GPRMC {
+             nav_system: Some(nmea_string.nav_system),
              time:       time,
              rmc_status: rmc_status,
              latitude:   latitude,
@@ -415,18 +714,207 @@ impl FromNmea for GPRMC {
              cog:        cog,
              date:       date,
              magvar:     magvar,
+             faa_mode:   faa_mode,
          }
     }
 }
 
 
+impl FromNmea for GPGLL {
+    fn from_nmea(nmea_string: &NMEA) -> GPGLL {
+
+        //There should be exactly 6 fields in a GPGLL sentence. So count the commas to ensure this
+        if nmea_count_fields(&nmea_string.sentence) != 6 {
+            //Return a GPGLL struct with all None values
+            return GPGLL::default();
+        }
+
+        //Also check the NMEA sentence NmeaType
+        if nmea_string.sentence_type != NmeaType::GPGLL {
+            return GPGLL::default();
+        }
+
+        let mut x = nmea_string.sentence.split(",").skip(1);
+
+        /*
+         * Since we already verified the number of commas in the GPGLL sentence,
+         * all the next() calls in the rest of the function will never return None.
+         * Therefore, none of the unwrap() calls will ever panic.
+         */
+
+        let latitude  = parse_nmea_lat(x.next().unwrap(), x.next().unwrap());
+        let longitude = parse_nmea_lon(x.next().unwrap(), x.next().unwrap());
+        let time      = x.next().unwrap().parse::<GpsTime>().ok();
+        let status    = x.next().unwrap().parse::<RmcStatus>().ok();
+
+        GPGLL {
+            nav_system: Some(nmea_string.nav_system),
+            latitude:   latitude,
+            longitude:  longitude,
+            time:       time,
+            status:     status,
+        }
+    }
+}
+
+impl FromNmea for GPVTG {
+    fn from_nmea(nmea_string: &NMEA) -> GPVTG {
+
+        //There should be at least 8 fields in a GPVTG sentence. So count the commas to ensure this
+        if nmea_count_fields(&nmea_string.sentence) < 8 {
+            //Return a GPVTG struct with all None values
+            return GPVTG::default();
+        }
+
+        //Also check the NMEA sentence NmeaType
+        if nmea_string.sentence_type != NmeaType::GPVTG {
+            return GPVTG::default();
+        }
+
+        let mut x = nmea_string.sentence.split(",").skip(1);
+
+        /*
+         * Since we already verified the number of commas in the GPVTG sentence,
+         * all the next() calls in the rest of the function will never return None.
+         * Therefore, none of the unwrap() calls will ever panic.
+         */
+
+        //The course and speed values are each followed by a unit indicator
+        //(T, M, N, K) which we skip over with nth(1)
+        let cog_true     = x.next().unwrap().parse::<f64>().ok();
+        let cog_magnetic = x.nth(1).unwrap().parse::<f64>().ok();
+        let sog_knots    = x.nth(1).unwrap().parse::<f64>().ok();
+        let sog_kmh      = x.nth(1).unwrap().parse::<f64>().ok();
+
+        GPVTG {
+            nav_system:   Some(nmea_string.nav_system),
+            cog_true:     cog_true,
+            cog_magnetic: cog_magnetic,
+            sog_knots:    sog_knots,
+            sog_kmh:      sog_kmh,
+        }
+    }
+}
+
+impl FromNmea for GPGSA {
+    fn from_nmea(nmea_string: &NMEA) -> GPGSA {
+
+        //There should be exactly 17 fields in a GPGSA sentence. So count the commas to ensure this
+        if nmea_count_fields(&nmea_string.sentence) != 17 {
+            //Return a GPGSA struct with all None values
+            return GPGSA::default();
+        }
+
+        //Also check the NMEA sentence NmeaType
+        if nmea_string.sentence_type != NmeaType::GPGSA {
+            return GPGSA::default();
+        }
+
+        let mut x = nmea_string.sentence.split(",").skip(1);
+
+        /*
+         * Since we already verified the number of commas in the GPGSA sentence,
+         * all the next() calls in the rest of the function will never return None.
+         * Therefore, none of the unwrap() calls will ever panic.
+         */
+
+        let mode = x.next().unwrap().parse::<GsaMode>().ok();
+        let fix  = x.next().unwrap().parse::<GsaFix>().ok();
+
+        //The next twelve fields are the PRNs of the active satellites
+        let mut satellites: [Option<u8>; 12] = Default::default();
+        for sat in satellites.iter_mut() {
+            *sat = x.next().unwrap().parse::<u8>().ok();
+        }
+
+        let pdop = x.next().unwrap().parse::<f64>().ok();
+        let hdop = x.next().unwrap().parse::<f64>().ok();
+        let vdop = x.next().unwrap().parse::<f64>().ok();
+
+        GPGSA {
+            nav_system: Some(nmea_string.nav_system),
+            mode:       mode,
+            fix:        fix,
+            satellites: satellites,
+            pdop:       pdop,
+            hdop:       hdop,
+            vdop:       vdop,
+        }
+    }
+}
+
+impl FromNmea for GPGSV {
+    fn from_nmea(nmea_string: &NMEA) -> GPGSV {
+
+        //There should be at least 7 fields in a GPGSV sentence (the three header
+        //fields plus one four-field satellite group). So count the commas to ensure this
+        if nmea_count_fields(&nmea_string.sentence) < 7 {
+            //Return a GPGSV struct with all None values
+            return GPGSV::default();
+        }
+
+        //Also check the NMEA sentence NmeaType
+        if nmea_string.sentence_type != NmeaType::GPGSV {
+            return GPGSV::default();
+        }
+
+        //Walk the fields with a single iterator so we never allocate. The three
+        //header fields are guaranteed present by the field-count check above.
+        let mut x = nmea_string.sentence.split(",").skip(1);
+
+        let total_messages     = x.next().unwrap().parse::<u8>().ok();
+        let message_number     = x.next().unwrap().parse::<u8>().ok();
+        let satellites_in_view = x.next().unwrap().parse::<u8>().ok();
+
+        //Each satellite group is four fields (PRN, elevation, azimuth, SNR).
+        //A sentence carries up to four of them; the last message may be short,
+        //so stop as soon as the iterator runs out of a complete group.
+        let mut satellites: [Option<SatelliteInView>; 4] = Default::default();
+        for sat in satellites.iter_mut() {
+            let (prn, elevation, azimuth, snr) =
+                match (x.next(), x.next(), x.next(), x.next()) {
+                    (Some(p), Some(e), Some(a), Some(s)) => (p, e, a, s),
+                    _ => break,
+                };
+
+            *sat = Some(SatelliteInView {
+                prn:       prn.parse::<u8>().ok(),
+                elevation: elevation.parse::<u8>().ok(),
+                azimuth:   azimuth.parse::<u16>().ok(),
+                snr:       snr.parse::<u8>().ok(),
+            });
+        }
+
+        GPGSV {
+            nav_system:         Some(nmea_string.nav_system),
+            total_messages:     total_messages,
+            message_number:     message_number,
+            satellites_in_view: satellites_in_view,
+            satellites:         satellites,
+        }
+    }
+}
+
+
 fn get_nmea_type(nmea: &str) -> NmeaType {
     //The unwrap() used here is completely safe and will never cause a panic
     //because the FromStr implemntation for <NmeaType> never returns an error
-    nmea.chars()                    //Make an iterator of all the characters in the sentence
-        .take_while(|&x| x != ',')  //Get all the characters up until the first ',' (comma)
-        .collect::<String>()        //Collect those characters into a string
-        .parse::<NmeaType>()        //Parse that string into an NmeaType
+    //The talker ID is the first two characters; the sentence code is the
+    //three that follow it. Slice the sentence code out directly (NMEA is ASCII,
+    //so byte offsets are character offsets) and parse it into an NmeaType.
+    nmea.get(2..5)
+        .unwrap_or("")
+        .parse::<NmeaType>()
+        .unwrap()
+}
+
+fn get_nav_system(nmea: &str) -> NavigationSystem {
+    //Just like get_nmea_type, the unwrap() is safe because the FromStr
+    //implementation for <NavigationSystem> never returns an error. The talker
+    //ID is the first two characters of the sentence.
+    nmea.get(0..2)
+        .unwrap_or("")
+        .parse::<NavigationSystem>()
         .unwrap()
 }
 
@@ -435,8 +923,8 @@ fn calc_nmea_checksum(nmea: &str) -> u8 {
 }
 
 fn parse_nmea_lat(latval: &str, dir: &str) -> Option<f64> {
-    let degrees = latval.chars().take(2).collect::<String>().parse::<f64>().ok()?;
-    let minutes = latval.chars().skip(2).collect::<String>().parse::<f64>().ok()?;
+    let degrees = latval.get(0..2)?.parse::<f64>().ok()?;
+    let minutes = latval.get(2..)?.parse::<f64>().ok()?;
 
     //Convert latval from degrees and minutes to decimal degrees,
     //And take the North vs South of the equator into consideration
@@ -448,8 +936,8 @@ fn parse_nmea_lat(latval: &str, dir: &str) -> Option<f64> {
 }
 
 fn parse_nmea_lon(lonval: &str, dir: &str) -> Option<f64> {
-    let degrees = lonval.chars().take(3).collect::<String>().parse::<f64>().ok()?;
-    let minutes = lonval.chars().skip(3).collect::<String>().parse::<f64>().ok()?;
+    let degrees = lonval.get(0..3)?.parse::<f64>().ok()?;
+    let minutes = lonval.get(3..)?.parse::<f64>().ok()?;
 
     //Convert lonval from degrees and minutes to decimal degrees,
     //And take the East vs West of the Prime Meridian into consideration
@@ -469,6 +957,38 @@ fn parse_nmea_magvar(magvar_val: &str, dir: &str) -> Option<f64> {
     }
 }
 
+/// The pivot for two-digit-year windowing. A two-digit year at or below this
+/// value is taken to be in the 2000s; anything above it is taken to be in the
+/// 1900s.
+///
+/// Note this is a *fixed* pivot, not the running "current two-digit year"
+/// described loosely in the request. A dynamic pivot would need a real-time
+/// clock, which this crate has no access to — and none at all in a `no_std`
+/// build — so a constant is the only option. The practical consequence is
+/// that years `71..=99` always resolve to the 1900s and `00..=70` to the
+/// 2000s, regardless of the actual date; `70` is chosen to stay comfortably
+/// ahead of the present so near-future dates still land in the 2000s.
+const TWO_DIGIT_YEAR_PIVOT: u16 = 70;
+
+/// Resolve an NMEA two-digit year into a full four-digit year by windowing
+/// around `TWO_DIGIT_YEAR_PIVOT`. For example `20` becomes 2020 rather than
+/// 1920, while `98` still becomes 1998.
+fn window_two_digit_year(yy: u16) -> u16 {
+    if yy <= TWO_DIGIT_YEAR_PIVOT {
+        2000 + yy
+    } else {
+        1900 + yy
+    }
+}
+
+fn merge_field<T>(dst: &mut Option<T>, src: Option<T>) {
+    //Only overwrite the destination when the new sentence actually carried a
+    //value, so that a still-valid field from an earlier sentence is preserved
+    if src.is_some() {
+        *dst = src;
+    }
+}
+
 fn nmea_count_fields(s: &str) -> u32 {
     s.chars().fold(0, |acc, x| {
         match x {